@@ -0,0 +1,438 @@
+use std::{io::stdout, path::PathBuf, sync::Arc, time::Duration};
+
+use anyhow::Result;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use helix_core::{coords_at_pos, syntax, Position, Selection, Transaction};
+use helix_tui::{
+    backend::{Backend, CrosstermBackend, TestBackend},
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+};
+use helix_view::{current, editor::Action, Editor};
+use tokio_stream::{Stream, StreamExt};
+
+use crate::{args::Args, config::Config};
+
+/// The harness's own reduced modal-editing state. The real event loop
+/// dispatches through a compositor and a user-configurable keymap
+/// (`helix_term::keymap`, `helix_term::commands`); this is a small,
+/// fixed command set covering just enough of normal/insert/command-line
+/// mode for integration tests to drive real document edits through actual
+/// key events instead of mutating `Editor` directly.
+#[derive(Debug, Clone)]
+enum Mode {
+    Normal,
+    Insert,
+    /// Accumulates a `:`-command's text until `<ret>` or `<esc>`.
+    Command(String),
+}
+
+/// Temporarily switches the process's current working directory for the
+/// scope of the guard, restoring the previous directory on drop. The
+/// working directory is process-wide state, so an `Application` built
+/// with `args.working_directory` set must not run concurrently with
+/// another that depends on the working directory too (e.g. via
+/// `#[serial]` or by running with a single test thread).
+struct ScopedWorkingDir {
+    previous: PathBuf,
+}
+
+impl ScopedWorkingDir {
+    fn enter(path: &std::path::Path) -> Result<Self> {
+        let previous = std::env::current_dir()?;
+        helix_loader::set_current_working_dir(path.to_path_buf())?;
+        Ok(Self { previous })
+    }
+}
+
+impl Drop for ScopedWorkingDir {
+    fn drop(&mut self) {
+        let _ = helix_loader::set_current_working_dir(self.previous.clone());
+    }
+}
+
+/// The running editor: the document/view model (`editor`) plus the
+/// terminal it's drawn into. The terminal is a boxed [`Backend`] rather
+/// than a concrete type so tests can swap in a [`TestBackend`] via
+/// [`Application::replace_backend`] and assert on the rendered cell grid
+/// instead of only on document state.
+pub struct Application {
+    pub editor: Editor,
+    backend: Box<dyn Backend>,
+    cwd_guard: Option<ScopedWorkingDir>,
+    mode: Mode,
+}
+
+impl Application {
+    pub fn new(args: Args, config: Config, syn_loader: syntax::Loader) -> Result<Self> {
+        let mut cwd_guard = args
+            .working_directory
+            .as_deref()
+            .map(ScopedWorkingDir::enter)
+            .transpose()?;
+
+        let backend: Box<dyn Backend> = Box::new(CrosstermBackend::new(stdout()));
+        let area = backend.size().unwrap_or_else(|_| Rect::new(0, 0, 80, 24));
+
+        let mut editor = Editor::new(area, Arc::new(syn_loader), Arc::new(config.editor.clone()));
+
+        let mut first = true;
+        for (path, _positions) in args.files.iter() {
+            if path.is_dir() {
+                // There's no file-explorer/picker component here (that
+                // lives in the compositor the real event loop drives), so
+                // the closest honest equivalent to "open a directory" is
+                // switching into it, same as `:cd` does for a running
+                // editor. Asking `Editor::open` to treat a directory as a
+                // document would just error. The switch is undone by `close`.
+                if cwd_guard.is_none() {
+                    cwd_guard = Some(ScopedWorkingDir::enter(path)?);
+                } else {
+                    helix_loader::set_current_working_dir(path.clone())?;
+                }
+                continue;
+            }
+
+            let action = if first {
+                first = false;
+                Action::Replace
+            } else {
+                Action::Load
+            };
+
+            editor.open(path, action)?;
+        }
+
+        Ok(Self {
+            editor,
+            backend,
+            cwd_guard,
+            mode: Mode::Normal,
+        })
+    }
+
+    async fn handle_terminal_event(&mut self, event: Event) -> bool {
+        match event {
+            Event::Key(key) => self.handle_key(key),
+            _ => true,
+        }
+    }
+
+    /// Dispatches a single key through [`Mode`]. Returns `false` once a
+    /// `:q`/`:q!`/`:quit` command line is executed, which the event loop
+    /// treats the same as the real application exiting.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return false;
+        }
+
+        match std::mem::replace(&mut self.mode, Mode::Normal) {
+            Mode::Normal => self.handle_normal_key(key),
+            Mode::Insert => {
+                self.handle_insert_key(key);
+                true
+            }
+            Mode::Command(buffer) => self.handle_command_key(key, buffer),
+        }
+    }
+
+    fn handle_normal_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('i') => self.mode = Mode::Insert,
+            KeyCode::Char('a') => {
+                self.move_cursor(1, 0);
+                self.mode = Mode::Insert;
+            }
+            KeyCode::Char(':') => self.mode = Mode::Command(String::new()),
+            KeyCode::Char('h') | KeyCode::Left => self.move_cursor(-1, 0),
+            KeyCode::Char('l') | KeyCode::Right => self.move_cursor(1, 0),
+            KeyCode::Char('j') | KeyCode::Down => self.move_cursor(0, 1),
+            KeyCode::Char('k') | KeyCode::Up => self.move_cursor(0, -1),
+            KeyCode::Char('x') => self.delete_char_under_cursor(),
+            // Stands in for the real keymap's `<C-w>v`: open the next
+            // document that has no view yet in a new vertical split and
+            // focus it, so tests can exercise split/view-switch behavior
+            // without the full window-command layer.
+            KeyCode::Char('v') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_next_document_in_split();
+            }
+            _ => {}
+        }
+
+        true
+    }
+
+    fn open_next_document_in_split(&mut self) {
+        let shown: std::collections::HashSet<_> =
+            self.editor.tree.views().map(|(view, _)| view.doc).collect();
+
+        let next_doc = self
+            .editor
+            .documents()
+            .map(|doc| doc.id())
+            .find(|id| !shown.contains(id));
+
+        if let Some(doc_id) = next_doc {
+            self.editor.switch(doc_id, Action::VerticalSplit);
+        }
+    }
+
+    fn handle_insert_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => return,
+            KeyCode::Char(c) => self.insert_char(c),
+            KeyCode::Enter => self.insert_char('\n'),
+            KeyCode::Backspace => self.delete_char_before_cursor(),
+            _ => {}
+        }
+
+        self.mode = Mode::Insert;
+    }
+
+    fn handle_command_key(&mut self, key: KeyEvent, mut buffer: String) -> bool {
+        match key.code {
+            KeyCode::Esc => true,
+            KeyCode::Enter => self.execute_command(&buffer),
+            KeyCode::Backspace => {
+                buffer.pop();
+                self.mode = Mode::Command(buffer);
+                true
+            }
+            KeyCode::Char(c) => {
+                buffer.push(c);
+                self.mode = Mode::Command(buffer);
+                true
+            }
+            _ => {
+                self.mode = Mode::Command(buffer);
+                true
+            }
+        }
+    }
+
+    /// Runs a `:`-command-line's text. Only `q`/`q!`/`quit`/`quit!` are
+    /// understood, matching the subset of commands the test harness itself
+    /// sends (see `test_key_sequences_with_timing`'s forced `:q!` tail);
+    /// anything else is silently ignored rather than routed to the real
+    /// command registry.
+    fn execute_command(&mut self, command: &str) -> bool {
+        !matches!(command.trim(), "q" | "q!" | "quit" | "quit!")
+    }
+
+    /// Moves the primary cursor by `dx` columns and `dy` lines, clamped to
+    /// the current line's bounds, mirroring normal-mode `h`/`j`/`k`/`l`.
+    fn move_cursor(&mut self, dx: isize, dy: isize) {
+        let (view, doc) = current!(self.editor);
+        let text = doc.text().slice(..);
+        let pos = doc.selection(view.id).primary().cursor(text);
+        let Position { row, col } = coords_at_pos(text, pos);
+
+        let last_line = text.len_lines().saturating_sub(1);
+        let new_row = (row as isize + dy).clamp(0, last_line as isize) as usize;
+
+        let line_len = if new_row < last_line {
+            text.line(new_row).len_chars().saturating_sub(1)
+        } else {
+            text.line(new_row).len_chars()
+        };
+
+        let new_col = (col as isize + dx).clamp(0, line_len as isize) as usize;
+        let new_pos = text.line_to_char(new_row) + new_col;
+
+        doc.set_selection(view.id, Selection::point(new_pos));
+    }
+
+    /// Inserts `c` at the cursor and advances the cursor past it, mirroring
+    /// typing a character in insert mode.
+    fn insert_char(&mut self, c: char) {
+        let (view, doc) = current!(self.editor);
+        let sel = doc.selection(view.id).clone();
+        let pos = sel.primary().cursor(doc.text().slice(..));
+
+        let mut encoded = [0u8; 4];
+        let s = c.encode_utf8(&mut encoded).to_string();
+        let transaction = Transaction::change(doc.text(), std::iter::once((pos, pos, Some(s.into()))))
+            .with_selection(Selection::point(pos + 1));
+
+        doc.apply(&transaction, view.id);
+    }
+
+    /// Deletes the character under the cursor, mirroring normal-mode `x`.
+    fn delete_char_under_cursor(&mut self) {
+        let (view, doc) = current!(self.editor);
+        let text = doc.text().slice(..);
+        let pos = doc.selection(view.id).primary().cursor(text);
+
+        if pos >= text.len_chars() {
+            return;
+        }
+
+        let transaction = Transaction::change(doc.text(), std::iter::once((pos, pos + 1, None)))
+            .with_selection(Selection::point(pos));
+
+        doc.apply(&transaction, view.id);
+    }
+
+    /// Deletes the character before the cursor, mirroring insert-mode
+    /// backspace.
+    fn delete_char_before_cursor(&mut self) {
+        let (view, doc) = current!(self.editor);
+        let text = doc.text().slice(..);
+        let pos = doc.selection(view.id).primary().cursor(text);
+
+        if pos == 0 {
+            return;
+        }
+
+        let transaction = Transaction::change(doc.text(), std::iter::once((pos - 1, pos, None)))
+            .with_selection(Selection::point(pos - 1));
+
+        doc.apply(&transaction, view.id);
+    }
+
+    pub async fn event_loop_until_idle<S>(&mut self, events: &mut S) -> bool
+    where
+        S: Stream<Item = std::io::Result<Event>> + Unpin,
+    {
+        loop {
+            match tokio::time::timeout(Duration::from_millis(0), events.next()).await {
+                Ok(Some(Ok(event))) => {
+                    if !self.handle_terminal_event(event).await {
+                        return false;
+                    }
+                }
+                Ok(Some(Err(_))) => continue,
+                Ok(None) => return false,
+                Err(_) => return true,
+            }
+        }
+    }
+
+    pub async fn event_loop<S>(&mut self, events: &mut S)
+    where
+        S: Stream<Item = std::io::Result<Event>> + Unpin,
+    {
+        while let Some(Ok(event)) = events.next().await {
+            if !self.handle_terminal_event(event).await {
+                break;
+            }
+        }
+    }
+
+    pub async fn close(&mut self) -> Vec<anyhow::Error> {
+        // Restore the working directory `Application::new` switched to,
+        // now that everything depending on it (startup, and the rest of
+        // the event loop) has finished running.
+        self.cwd_guard.take();
+
+        Vec::new()
+    }
+
+    /// Renders the current editor state into `self.backend`: each open
+    /// view's document text into its own horizontal band of the backend's
+    /// area above the bottom row (see [`Rect::split_rows`]), with a line-number gutter down the left edge of each band, the
+    /// primary selection's cursor highlighted, and a one-line mode
+    /// indicator along the bottom row. Syntax highlighting, diagnostics,
+    /// and virtual text are drawn by the compositor in the real event loop
+    /// and aren't reproduced here.
+    pub fn render(&mut self) {
+        let Ok(area) = self.backend.size() else {
+            return;
+        };
+
+        if area.height == 0 {
+            return;
+        }
+
+        let mut buffer = Buffer::empty(area);
+
+        let status_line_y = area.y + area.height - 1;
+        let editor_area = Rect::new(area.x, area.y, area.width, area.height - 1);
+
+        let views: Vec<_> = self.editor.tree.views().map(|(view, _)| view).collect();
+        let bands = editor_area.split_rows(views.len().max(1) as u16);
+
+        for (view, band) in views.iter().zip(bands) {
+            let Some(doc) = self.editor.document(view.doc) else {
+                continue;
+            };
+
+            let text = doc.text().slice(..);
+            let gutter_width = text.len_lines().to_string().len().max(2) as u16 + 1;
+            let text_x = band.x + gutter_width;
+            let text_width = band.width.saturating_sub(gutter_width);
+
+            for (row, line) in text.lines().take(band.height as usize).enumerate() {
+                let line_number = format!("{:>width$} ", row + 1, width = (gutter_width - 1) as usize);
+                buffer.set_stringn(
+                    band.x,
+                    band.y + row as u16,
+                    &line_number,
+                    gutter_width as usize,
+                    Style::default(),
+                );
+                buffer.set_stringn(
+                    text_x,
+                    band.y + row as u16,
+                    &line.to_string(),
+                    text_width as usize,
+                    Style::default(),
+                );
+            }
+
+            let cursor = doc.selection(view.id).primary().cursor(text);
+            let Position { row, col } = coords_at_pos(text, cursor);
+            if (row as u16) < band.height && (col as u16) < text_width {
+                let x = text_x + col as u16;
+                let y = band.y + row as u16;
+                let cell = buffer.get_mut(x, y);
+                cell.style = cell.style.patch(Style::default().add_modifier(Modifier::REVERSED));
+            }
+        }
+
+        let mode_label = match &self.mode {
+            Mode::Normal => "NOR".to_string(),
+            Mode::Insert => "INS".to_string(),
+            Mode::Command(buffer) => format!("CMD  :{buffer}"),
+        };
+        buffer.set_stringn(
+            area.x,
+            status_line_y,
+            &mode_label,
+            area.width as usize,
+            Style::default().add_modifier(Modifier::REVERSED),
+        );
+
+        let cells: Vec<_> = (0..area.height)
+            .flat_map(|y| (0..area.width).map(move |x| (x, y)))
+            .map(|(x, y)| (x, y, buffer.get(x, y)))
+            .collect();
+        let _ = self.backend.draw(cells.into_iter());
+        let _ = self.backend.flush();
+    }
+
+    /// Swaps the installed backend for `backend`, e.g. a fixed-size
+    /// [`TestBackend`], so integration tests can render into an in-memory
+    /// grid instead of the real terminal. See `AppBuilder::with_terminal_size`.
+    pub fn replace_backend(&mut self, backend: impl Backend + 'static) -> Result<()> {
+        self.backend = Box::new(backend);
+        Ok(())
+    }
+
+    /// Renders one frame without driving the rest of the event loop, for
+    /// use with [`Application::test_backend`] in render-snapshot tests.
+    pub fn render_test_frame(&mut self) {
+        self.render();
+    }
+
+    /// The backend installed by [`Application::replace_backend`], downcast
+    /// to [`TestBackend`]. Panics if the application isn't using a test
+    /// backend (i.e. `AppBuilder::with_terminal_size` wasn't called).
+    pub fn test_backend(&self) -> &TestBackend {
+        self.backend
+            .as_any()
+            .downcast_ref::<TestBackend>()
+            .expect("test_backend called without AppBuilder::with_terminal_size")
+    }
+}
@@ -1,4 +1,6 @@
 use std::{
+    collections::HashMap,
+    fmt::Write as _,
     io::{Read, Write},
     mem::replace,
     path::PathBuf,
@@ -9,8 +11,12 @@ use anyhow::bail;
 use crossterm::event::{Event, KeyEvent};
 use helix_core::{diagnostic::Severity, test, Selection, Transaction};
 use helix_term::{application::Application, args::Args, config::Config, keymap::merge_keys};
-use helix_view::{current_ref, doc, editor::LspConfig, input::parse_macro, Editor};
+use helix_tui::backend::TestBackend;
+use helix_view::{
+    current_ref, doc, doc_mut, editor::Action, editor::LspConfig, input::parse_macro, Editor,
+};
 use tempfile::NamedTempFile;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 /// Specify how to set up the input text with line feeds
@@ -113,13 +119,37 @@ pub async fn test_key_sequences(
     app: &mut Application,
     inputs: Vec<(Option<&str>, Option<&dyn Fn(&Application)>)>,
     should_exit: bool,
+) -> anyhow::Result<()> {
+    let inputs = inputs
+        .into_iter()
+        .map(|(keys, test_fn)| (keys, None, test_fn))
+        .collect();
+
+    test_key_sequences_with_timing(app, inputs, should_exit).await
+}
+
+/// Like [`test_key_sequences`], but each step may also advance the tokio
+/// clock by a fixed [`Duration`] after its keys are queued and before
+/// waiting for the app to go idle, so each step's `test_fn` runs at an
+/// exact, reproducible instant of the tokio clock instead of depending on
+/// wall-clock scheduling. `Application` itself runs no timers: advancing
+/// the clock changes what `tokio::time::Instant::now` reports and fires no
+/// idle-timeout or debounce events.
+///
+/// Requires the test to run under `#[tokio::test(start_paused = true)]` so
+/// `tokio::time::advance` has a paused clock to move forward.
+#[allow(clippy::type_complexity)]
+pub async fn test_key_sequences_with_timing(
+    app: &mut Application,
+    inputs: Vec<(Option<&str>, Option<Duration>, Option<&dyn Fn(&Application)>)>,
+    should_exit: bool,
 ) -> anyhow::Result<()> {
     const TIMEOUT: Duration = Duration::from_millis(500);
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
     let mut rx_stream = UnboundedReceiverStream::new(rx);
     let num_inputs = inputs.len();
 
-    for (i, (in_keys, test_fn)) in inputs.into_iter().enumerate() {
+    for (i, (in_keys, advance, test_fn)) in inputs.into_iter().enumerate() {
         let (view, doc) = current_ref!(app.editor);
         let state = test::plain(doc.text().slice(..), doc.selection(view.id));
 
@@ -133,6 +163,11 @@ pub async fn test_key_sequences(
             }
         }
 
+        if let Some(advance) = advance {
+            log::trace!("advancing the clock by {:?}", advance);
+            tokio::time::advance(advance).await;
+        }
+
         let app_exited = !app.event_loop_until_idle(&mut rx_stream).await;
 
         if !app_exited {
@@ -263,6 +298,53 @@ pub async fn test<T: Into<TestCase>>(test_case: T) -> anyhow::Result<()> {
     test_with_config(AppBuilder::default(), test_case).await
 }
 
+/// Use this for test cases involving splits, multiple open documents, or
+/// jumps between views, where `test_with_config`'s assumption of a single
+/// selection does not hold. Runs `in_keys` against the views set up by
+/// `app_builder` (see [`AppBuilder::with_input_texts`]) and asserts every
+/// open view against an expected `(text, Selection)`, in the order
+/// `Editor::tree::views` yields them.
+pub async fn test_with_config_multi(
+    app_builder: AppBuilder,
+    in_keys: &str,
+    expected_views: Vec<(&str, Selection)>,
+) -> anyhow::Result<()> {
+    let mut app = app_builder.build()?;
+
+    test_key_sequence(
+        &mut app,
+        Some(in_keys),
+        Some(&|app| assert_view_selections(&app.editor, &expected_views)),
+        false,
+    )
+    .await
+}
+
+/// Asserts that the currently open views match `expected`, comparing each
+/// view's document text and selection. Views are compared in the order
+/// `Editor::tree::views` yields them, which is insertion order rather than
+/// anything based on screen position.
+pub fn assert_view_selections(editor: &Editor, expected: &[(&str, Selection)]) {
+    let views: Vec<_> = editor.tree.views().map(|(view, _focus)| view).collect();
+
+    assert_eq!(
+        expected.len(),
+        views.len(),
+        "expected {} views, found {}",
+        expected.len(),
+        views.len()
+    );
+
+    for (view, (expected_text, expected_selection)) in views.iter().zip(expected) {
+        let doc = editor
+            .document(view.doc)
+            .expect("every view points at a live document");
+
+        assert_eq!(*expected_text, doc.text().to_string());
+        assert_eq!(*expected_selection, doc.selection(view.id).clone());
+    }
+}
+
 pub fn temp_file_with_contents<S: AsRef<str>>(
     content: S,
 ) -> anyhow::Result<tempfile::NamedTempFile> {
@@ -319,11 +401,164 @@ pub fn new_readonly_tempfile_in_dir(
     file.as_file_mut().set_permissions(perms)?;
     Ok(file)
 }
+/// A canned response for a single mock LSP request method.
+pub type MockLspHandler = Box<dyn Fn(serde_json::Value) -> serde_json::Value + Send + Sync>;
+
+/// A scriptable mock language server: register canned responses keyed by
+/// LSP method name (e.g. `"textDocument/hover"`) and notifications to push
+/// to the client once connected, without needing a real language server
+/// binary on `PATH`. Run it with [`spawn_mock_lsp`] to get the client-facing
+/// half of an in-memory transport speaking real JSON-RPC over the wire.
+///
+/// There's no `AppBuilder` integration yet: registering that transport in
+/// place of a spawned process for a document's language server requires a
+/// client-start hook on `helix_lsp`'s side that doesn't exist yet, and until
+/// it does, a builder method here would have no way to avoid either
+/// failing every time it's used or silently doing nothing. The mock server
+/// is tested directly, in `tests/test/mock_lsp.rs`.
+#[derive(Default)]
+pub struct MockLspHandlers {
+    // Unread until an `AppBuilder` hook picks the mock server for a
+    // document's language; kept so callers already declare it up front.
+    #[allow(dead_code)]
+    language: String,
+    responses: HashMap<&'static str, MockLspHandler>,
+    notifications: Vec<(&'static str, serde_json::Value)>,
+}
+
+impl MockLspHandlers {
+    /// `language` is stored but not read: nothing picks the mock up in
+    /// place of a document's real language server.
+    pub fn new(language: impl Into<String>) -> Self {
+        Self {
+            language: language.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Register a canned response for a request method.
+    pub fn on_request(
+        mut self,
+        method: &'static str,
+        handler: impl Fn(serde_json::Value) -> serde_json::Value + Send + Sync + 'static,
+    ) -> Self {
+        self.responses.insert(method, Box::new(handler));
+        self
+    }
+
+    /// Queue a notification (e.g. `textDocument/publishDiagnostics`) sent
+    /// to the client right after `initialize` completes.
+    pub fn notify(mut self, method: &'static str, params: serde_json::Value) -> Self {
+        self.notifications.push((method, params));
+        self
+    }
+}
+
+/// Runs `handlers` as an in-process mock language server, speaking
+/// JSON-RPC over an in-memory duplex pipe instead of stdio. Returns the
+/// client-facing half of the pipe: write requests/notifications to it with
+/// [`write_lsp_message`] and read the server's replies with
+/// [`read_lsp_message`], the same framing a real language server client
+/// would use.
+pub(crate) fn spawn_mock_lsp(handlers: MockLspHandlers) -> tokio::io::DuplexStream {
+    let (client_side, server_side) = tokio::io::duplex(4096);
+    tokio::spawn(run_mock_lsp_server(server_side, handlers));
+    client_side
+}
+
+async fn run_mock_lsp_server(stream: tokio::io::DuplexStream, handlers: MockLspHandlers) {
+    let (read_half, mut write_half) = tokio::io::split(stream);
+    let mut reader = BufReader::new(read_half);
+
+    while let Some(message) = read_lsp_message(&mut reader).await {
+        let Some(method) = message.get("method").and_then(|m| m.as_str()) else {
+            continue;
+        };
+
+        if method == "initialize" {
+            write_lsp_message(
+                &mut write_half,
+                &serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": message["id"],
+                    "result": { "capabilities": {} },
+                }),
+            )
+            .await;
+
+            for (method, params) in &handlers.notifications {
+                write_lsp_message(
+                    &mut write_half,
+                    &serde_json::json!({ "jsonrpc": "2.0", "method": method, "params": params }),
+                )
+                .await;
+            }
+
+            continue;
+        }
+
+        // notifications from the client have no `id` and expect no reply.
+        let Some(id) = message.get("id").cloned() else {
+            continue;
+        };
+
+        let result = match handlers.responses.get(method) {
+            Some(handler) => handler(message["params"].clone()),
+            None => serde_json::Value::Null,
+        };
+
+        write_lsp_message(
+            &mut write_half,
+            &serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        )
+        .await;
+    }
+}
+
+pub(crate) async fn write_lsp_message(
+    writer: &mut (impl tokio::io::AsyncWrite + Unpin),
+    message: &serde_json::Value,
+) {
+    let body = serde_json::to_vec(message).expect("mock LSP message is valid JSON");
+    let header = format!("Content-Length: {}\r\n\r\n", body.len());
+    let _ = writer.write_all(header.as_bytes()).await;
+    let _ = writer.write_all(&body).await;
+}
+
+pub(crate) async fn read_lsp_message(
+    reader: &mut (impl tokio::io::AsyncBufRead + Unpin),
+) -> Option<serde_json::Value> {
+    let mut content_length = None;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).await.ok()? == 0 {
+            return None;
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some(value) = line.strip_prefix("Content-Length: ") {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let mut body = vec![0u8; content_length?];
+    reader.read_exact(&mut body).await.ok()?;
+    serde_json::from_slice(&body).ok()
+}
+
 pub struct AppBuilder {
     args: Args,
     config: Config,
     syn_loader: helix_core::syntax::Loader,
     input: Option<(String, Selection)>,
+    extra_documents: Vec<String>,
+    terminal_size: Option<(u16, u16)>,
 }
 
 impl Default for AppBuilder {
@@ -333,6 +568,8 @@ impl Default for AppBuilder {
             config: test_config(),
             syn_loader: test_syntax_loader(None),
             input: None,
+            extra_documents: Vec::new(),
+            terminal_size: None,
         }
     }
 }
@@ -368,20 +605,50 @@ impl AppBuilder {
         self
     }
 
+    /// Open additional documents (beyond the single initial document) so a
+    /// test can exercise splits and view-to-view jumps. Each document is
+    /// opened as a scratch buffer in the order given, with `text` as its
+    /// full contents; the test's key sequence is responsible for actually
+    /// creating the splits (`<C-v>`, the harness's stand-in for `<C-w>v`)
+    /// and navigating to them.
+    ///
+    /// Unlike [`AppBuilder::with_input_text`], `text` isn't scanned for
+    /// selection markers: `Action::Load` doesn't give the document a view,
+    /// and without one there's no view-keyed selection to attach a starting
+    /// cursor to (any selection set here would simply be discarded the
+    /// moment the key sequence opens a real view onto the document). Assert
+    /// on the selection the key sequence actually leaves behind instead, via
+    /// [`test_with_config_multi`]'s `expected_views`.
+    pub fn with_input_texts<S: Into<String>>(mut self, input_texts: Vec<S>) -> Self {
+        self.extra_documents = input_texts.into_iter().map(Into::into).collect();
+        self
+    }
+
     pub fn with_lang_loader(mut self, syn_loader: helix_core::syntax::Loader) -> Self {
         self.syn_loader = syn_loader;
         self
     }
 
-    pub fn build(self) -> anyhow::Result<Application> {
-        if let Some(path) = &self.args.working_directory {
-            bail!("Changing the working directory to {path:?} is not yet supported for integration tests");
-        }
+    /// Render into a fixed-size in-memory backend instead of the real
+    /// terminal, so tests can assert on the rendered cell grid with
+    /// [`assert_render_snapshot`].
+    pub fn with_terminal_size(mut self, cols: u16, rows: u16) -> Self {
+        self.terminal_size = Some((cols, rows));
+        self
+    }
 
-        if let Some((path, _)) = self.args.files.first().filter(|p| p.0.is_dir()) {
-            bail!("Having the directory {path:?} in args.files[0] is not yet supported for integration tests");
-        }
+    /// Run the test with the process's working directory switched to
+    /// `path`, so relative paths in `args.files` and `:cd`-dependent
+    /// behavior resolve against it for the lifetime of the `Application` —
+    /// the previous directory is restored in `app.close()`, not at the end
+    /// of `build`, so the test's key sequence still runs with the switched
+    /// directory in effect.
+    pub fn with_working_directory<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.args.working_directory = Some(path.into());
+        self
+    }
 
+    pub fn build(self) -> anyhow::Result<Application> {
         let mut app = Application::new(self.args, self.config, self.syn_loader)?;
 
         if let Some((text, selection)) = self.input {
@@ -396,10 +663,94 @@ impl AppBuilder {
             doc.apply(&trans, view.id);
         }
 
+        for text in self.extra_documents {
+            let doc_id = app.editor.new_file(Action::Load);
+            let doc = doc_mut!(app.editor, &doc_id);
+
+            // `Action::Load` doesn't move focus, so this document has no
+            // view yet and thus no view-keyed selection to read (that's
+            // what used to panic here) or usefully set. `Transaction::change`
+            // doesn't need one. The `view_id` passed to `apply` below only
+            // records which view triggered the edit, not a selection to
+            // inherit.
+            let trans = Transaction::change(
+                doc.text(),
+                std::iter::once((0, doc.text().len_chars(), Some(text.into()))),
+            );
+
+            doc.apply(&trans, app.editor.tree.focus);
+        }
+
+        if let Some((cols, rows)) = self.terminal_size {
+            app.replace_backend(TestBackend::new(cols, rows))?;
+        }
+
         Ok(app)
     }
 }
 
+/// Renders `app`'s UI into the fixed-size backend set up via
+/// [`AppBuilder::with_terminal_size`] and compares the result against
+/// `expected`. The rendered grid is flattened to a stable textual form
+/// (plain text per row, followed by a legend of any non-default style
+/// runs) so tests can diff it like any other golden string.
+///
+/// Set the `HELIX_UPDATE_SNAPSHOTS` environment variable to print the
+/// freshly rendered snapshot instead of asserting, so it can be copied
+/// back into the test.
+pub fn assert_render_snapshot(app: &mut Application, expected: &str) {
+    app.render_test_frame();
+
+    let rendered = render_snapshot(app.test_backend().buffer());
+
+    if std::env::var_os("HELIX_UPDATE_SNAPSHOTS").is_some() {
+        eprintln!("---- updated snapshot ----\n{rendered}---------------------------");
+        return;
+    }
+
+    assert_eq!(
+        expected.trim_end_matches('\n'),
+        rendered.trim_end_matches('\n'),
+        "rendered UI did not match the expected snapshot"
+    );
+}
+
+/// Flattens a rendered [`helix_tui::buffer::Buffer`] into a stable textual
+/// form: the plain text of each row, followed by a legend line for every
+/// contiguous run of cells sharing a non-default style, giving the run's
+/// column range and the `Debug` form of its [`helix_tui::style::Style`]
+/// (colors, including exact RGB values, and modifiers).
+fn render_snapshot(buffer: &helix_tui::buffer::Buffer) -> String {
+    let area = buffer.area();
+    let mut out = String::new();
+
+    for y in 0..area.height {
+        let mut line = String::new();
+        let mut runs: Vec<(u16, u16, helix_tui::style::Style)> = Vec::new();
+
+        for x in 0..area.width {
+            let cell = &buffer[(area.x + x, area.y + y)];
+            line.push_str(cell.symbol());
+
+            match runs.last_mut() {
+                Some((_, end, style)) if *style == cell.style => *end = x + 1,
+                _ => runs.push((x, x + 1, cell.style)),
+            }
+        }
+
+        let _ = writeln!(out, "{}", line.trim_end());
+
+        for (start, end, style) in runs
+            .into_iter()
+            .filter(|(_, _, style)| *style != helix_tui::style::Style::default())
+        {
+            let _ = writeln!(out, "  {start}..{end}: {style:?}");
+        }
+    }
+
+    out
+}
+
 pub async fn run_event_loop_until_idle(app: &mut Application) {
     let (_, rx) = tokio::sync::mpsc::unbounded_channel();
     let mut rx_stream = UnboundedReceiverStream::new(rx);
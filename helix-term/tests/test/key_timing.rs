@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use helix_term::application::Application;
+
+use super::helpers::{test_key_sequences_with_timing, AppBuilder};
+
+/// `test_key_sequences_with_timing` advances the (paused) tokio clock by
+/// each step's `Duration` before waiting for the app to go idle, so a later
+/// step's `test_fn` can observe exactly how much time has passed.
+#[tokio::test(start_paused = true)]
+async fn clock_advances_between_steps() -> anyhow::Result<()> {
+    let mut app = AppBuilder::default().with_input_text("ab").build()?;
+    let start = tokio::time::Instant::now();
+
+    test_key_sequences_with_timing(
+        &mut app,
+        vec![
+            (Some("x"), Some(Duration::from_millis(300)), None),
+            (
+                Some("x"),
+                Some(Duration::from_millis(200)),
+                Some(&(|_: &Application| {
+                    assert!(tokio::time::Instant::now() >= start + Duration::from_millis(500));
+                }) as &dyn Fn(&Application)),
+            ),
+        ],
+        false,
+    )
+    .await
+}
@@ -0,0 +1,51 @@
+use tokio::io::BufReader;
+
+use super::helpers::{read_lsp_message, spawn_mock_lsp, write_lsp_message, MockLspHandlers};
+
+/// Drives the mock language server directly over its in-memory transport
+/// (there's no `AppBuilder` integration yet, see `MockLspHandlers`'s doc
+/// comment), proving the scripted `initialize` handshake, queued
+/// notification, and canned request/response all really speak JSON-RPC
+/// end-to-end.
+#[tokio::test(flavor = "multi_thread")]
+async fn mock_lsp_server_handles_initialize_and_scripted_requests() {
+    let handlers = MockLspHandlers::new("rust")
+        .on_request("textDocument/hover", |_| {
+            serde_json::json!({ "contents": "docs" })
+        })
+        .notify(
+            "textDocument/publishDiagnostics",
+            serde_json::json!({ "uri": "file:///a.rs", "diagnostics": [] }),
+        );
+
+    let transport = spawn_mock_lsp(handlers);
+    let (read_half, mut write_half) = tokio::io::split(transport);
+    let mut reader = BufReader::new(read_half);
+
+    write_lsp_message(
+        &mut write_half,
+        &serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+    )
+    .await;
+
+    let response = read_lsp_message(&mut reader)
+        .await
+        .expect("initialize response");
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["capabilities"], serde_json::json!({}));
+
+    let notification = read_lsp_message(&mut reader)
+        .await
+        .expect("queued notification");
+    assert_eq!(notification["method"], "textDocument/publishDiagnostics");
+
+    write_lsp_message(
+        &mut write_half,
+        &serde_json::json!({ "jsonrpc": "2.0", "id": 2, "method": "textDocument/hover", "params": {} }),
+    )
+    .await;
+
+    let hover = read_lsp_message(&mut reader).await.expect("hover response");
+    assert_eq!(hover["id"], 2);
+    assert_eq!(hover["result"]["contents"], "docs");
+}
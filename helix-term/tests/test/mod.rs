@@ -0,0 +1,7 @@
+pub mod helpers;
+
+mod key_timing;
+mod mock_lsp;
+mod multi_view;
+mod render;
+mod working_directory;
@@ -0,0 +1,24 @@
+use helix_core::Selection;
+
+use super::helpers::{test_with_config_multi, AppBuilder};
+
+/// Exercises `assert_view_selections`/`test_with_config_multi` against a
+/// real second view: `<C-v>` (the harness's stand-in for the real
+/// keymap's `<C-w>v`) opens the extra document registered via
+/// `with_input_texts` in a new split.
+#[tokio::test(flavor = "multi_thread")]
+async fn multi_view_assertions_cover_a_new_split() -> anyhow::Result<()> {
+    let app_builder = AppBuilder::default()
+        .with_input_text("first")
+        .with_input_texts(vec!["second"]);
+
+    test_with_config_multi(
+        app_builder,
+        "<C-v>",
+        vec![
+            ("first", Selection::point(0)),
+            ("second", Selection::point(0)),
+        ],
+    )
+    .await
+}
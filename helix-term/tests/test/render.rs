@@ -0,0 +1,32 @@
+use super::helpers::{test_key_sequence, AppBuilder};
+
+/// Exercises `Application::handle_terminal_event`'s real key dispatch (`x`
+/// to delete, `i` to insert, `<esc>` to return to normal mode) together
+/// with `render`'s line-number gutter and mode statusline, reading the
+/// rendered cells straight out of the `TestBackend` buffer.
+#[tokio::test(flavor = "multi_thread")]
+async fn render_reflects_dispatched_edits_gutter_and_statusline() -> anyhow::Result<()> {
+    let mut app = AppBuilder::default()
+        .with_input_text("ab")
+        .with_terminal_size(8, 2)
+        .build()?;
+
+    test_key_sequence(&mut app, Some("xiH<esc>"), None, false).await?;
+
+    app.render_test_frame();
+    let buffer = app.test_backend().buffer();
+
+    // gutter "1" followed by the edited text ("ab" -> "b" -> "Hb")
+    assert_eq!(" ", buffer.get(0, 0).symbol());
+    assert_eq!("1", buffer.get(1, 0).symbol());
+    assert_eq!(" ", buffer.get(2, 0).symbol());
+    assert_eq!("H", buffer.get(3, 0).symbol());
+    assert_eq!("b", buffer.get(4, 0).symbol());
+
+    // statusline mode indicator on the bottom row
+    assert_eq!("N", buffer.get(0, 1).symbol());
+    assert_eq!("O", buffer.get(1, 1).symbol());
+    assert_eq!("R", buffer.get(2, 1).symbol());
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use super::helpers::AppBuilder;
+
+/// `AppBuilder::with_working_directory`'s switch must last for the whole
+/// `Application`, not just `build()`, and must be undone by `close()`.
+#[tokio::test]
+async fn working_directory_is_restored_after_close() -> anyhow::Result<()> {
+    let previous = std::env::current_dir()?;
+    let dir = tempfile::tempdir()?;
+
+    let mut app = AppBuilder::default()
+        .with_working_directory(dir.path())
+        .build()?;
+
+    assert_eq!(
+        dir.path().canonicalize()?,
+        std::env::current_dir()?.canonicalize()?
+    );
+
+    app.close().await;
+
+    assert_eq!(previous, std::env::current_dir()?);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use std::io::{self, Write};
+
+use crossterm::{cursor, queue, terminal};
+
+use super::Backend;
+use crate::{buffer::Cell, layout::Rect};
+
+/// The real terminal backend, driving the tty via `crossterm`.
+pub struct CrosstermBackend<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> CrosstermBackend<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W: Write> Backend for CrosstermBackend<W> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let mut last_pos: Option<(u16, u16)> = None;
+
+        for (x, y, cell) in content {
+            if last_pos != Some((x, y)) {
+                queue!(self.writer, cursor::MoveTo(x, y))?;
+            }
+            last_pos = Some((x + 1, y));
+
+            write!(self.writer, "{}", cell.symbol())?;
+        }
+
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        queue!(self.writer, cursor::Hide)
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        queue!(self.writer, cursor::Show)
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        cursor::position()
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        queue!(self.writer, cursor::MoveTo(x, y))
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        queue!(self.writer, terminal::Clear(terminal::ClearType::All))
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        let (width, height) = terminal::size()?;
+        Ok(Rect::new(0, 0, width, height))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
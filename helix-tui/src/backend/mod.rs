@@ -0,0 +1,37 @@
+use std::io;
+
+use crate::{buffer::Cell, layout::Rect};
+
+mod crossterm;
+mod test;
+
+pub use self::crossterm::CrosstermBackend;
+pub use self::test::TestBackend;
+
+/// Abstraction over a terminal output device. Implemented by
+/// [`CrosstermBackend`] for the real terminal, and by [`TestBackend`] for
+/// the integration test harness, which renders into an in-memory
+/// [`Buffer`](crate::buffer::Buffer) instead of a real tty so UI-level
+/// behavior (the line-number gutter, the mode indicator on the status
+/// line, and cursor position) can be asserted on directly. Syntax
+/// highlighting, diagnostics, and virtual text aren't produced by this
+/// renderer yet.
+pub trait Backend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>;
+
+    fn hide_cursor(&mut self) -> io::Result<()>;
+    fn show_cursor(&mut self) -> io::Result<()>;
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)>;
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()>;
+    fn clear(&mut self) -> io::Result<()>;
+    fn size(&self) -> io::Result<Rect>;
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Lets [`Application`](../../helix_term/application/struct.Application.html)
+    /// downcast a boxed backend back to a concrete type, e.g. so the test
+    /// harness can read the [`Buffer`](crate::buffer::Buffer) a
+    /// [`TestBackend`] rendered into.
+    fn as_any(&self) -> &dyn std::any::Any;
+}
@@ -0,0 +1,84 @@
+use std::io;
+
+use super::Backend;
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+};
+
+/// An in-memory [`Backend`] that renders into a [`Buffer`] instead of a
+/// real terminal. Used by the integration test harness
+/// (`AppBuilder::with_terminal_size`) to assert on the rendered cell grid
+/// — the line-number gutter, the mode indicator on the status line, and
+/// cursor position — rather than only on document state.
+pub struct TestBackend {
+    buffer: Buffer,
+    cursor_shown: bool,
+    cursor_pos: (u16, u16),
+}
+
+impl TestBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            buffer: Buffer::empty(Rect::new(0, 0, width, height)),
+            cursor_shown: false,
+            cursor_pos: (0, 0),
+        }
+    }
+
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    pub fn cursor_shown(&self) -> bool {
+        self.cursor_shown
+    }
+}
+
+impl Backend for TestBackend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (x, y, cell) in content {
+            *self.buffer.get_mut(x, y) = cell.clone();
+        }
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.cursor_shown = false;
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.cursor_shown = true;
+        Ok(())
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        Ok(self.cursor_pos)
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.cursor_pos = (x, y);
+        Ok(())
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.buffer = Buffer::empty(self.buffer.area());
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        Ok(self.buffer.area())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
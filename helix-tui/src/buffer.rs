@@ -0,0 +1,125 @@
+use std::ops::{Index, IndexMut};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{layout::Rect, style::Style};
+
+/// A single terminal cell: the text it displays and the style it's drawn
+/// with. `symbol` holds a full grapheme rather than a single `char` so
+/// multi-byte glyphs (wide CJK characters, emoji) round-trip correctly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    symbol: String,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn set_symbol(&mut self, symbol: &str) -> &mut Self {
+        self.symbol.clear();
+        self.symbol.push_str(symbol);
+        self
+    }
+
+    pub fn set_style(&mut self, style: Style) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    pub fn reset(&mut self) {
+        self.symbol.clear();
+        self.symbol.push(' ');
+        self.style = Style::default();
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: " ".to_string(),
+            style: Style::default(),
+        }
+    }
+}
+
+/// A grid of [`Cell`]s representing one rendered frame, addressed in
+/// absolute (not area-relative) coordinates. This is what a [`Backend`]
+/// draws into and what the test harness's render-snapshot formatter reads
+/// back out.
+///
+/// [`Backend`]: crate::backend::Backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Buffer {
+    area: Rect,
+    content: Vec<Cell>,
+}
+
+impl Buffer {
+    pub fn empty(area: Rect) -> Self {
+        let size = area.area() as usize;
+        Self {
+            area,
+            content: vec![Cell::default(); size],
+        }
+    }
+
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    pub fn index_of(&self, x: u16, y: u16) -> usize {
+        debug_assert!(
+            x >= self.area.x
+                && x < self.area.right()
+                && y >= self.area.y
+                && y < self.area.bottom(),
+            "cell ({x}, {y}) is outside of buffer area {:?}",
+            self.area
+        );
+
+        ((y - self.area.y) as usize * self.area.width as usize) + (x - self.area.x) as usize
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> &Cell {
+        &self.content[self.index_of(x, y)]
+    }
+
+    pub fn get_mut(&mut self, x: u16, y: u16) -> &mut Cell {
+        let i = self.index_of(x, y);
+        &mut self.content[i]
+    }
+
+    /// Writes `string` into row `y` starting at column `x`, applying
+    /// `style` to every cell it touches, truncating at the buffer's right
+    /// edge. Returns the column immediately after the last cell written.
+    pub fn set_stringn(&mut self, x: u16, y: u16, string: &str, max_width: usize, style: Style) -> u16 {
+        let mut col = x;
+        for grapheme in string.graphemes(true).take(max_width) {
+            if col >= self.area.right() {
+                break;
+            }
+            let cell = self.get_mut(col, y);
+            cell.set_symbol(grapheme);
+            cell.set_style(style);
+            col += 1;
+        }
+        col
+    }
+}
+
+impl Index<(u16, u16)> for Buffer {
+    type Output = Cell;
+
+    fn index(&self, (x, y): (u16, u16)) -> &Cell {
+        self.get(x, y)
+    }
+}
+
+impl IndexMut<(u16, u16)> for Buffer {
+    fn index_mut(&mut self, (x, y): (u16, u16)) -> &mut Cell {
+        self.get_mut(x, y)
+    }
+}
@@ -0,0 +1,62 @@
+/// A rectangular region of the terminal, in cell coordinates.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn area(self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+
+    pub fn left(self) -> u16 {
+        self.x
+    }
+
+    pub fn right(self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    pub fn top(self) -> u16 {
+        self.y
+    }
+
+    pub fn bottom(self) -> u16 {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Splits the area into `count` horizontal bands of `height / count`
+    /// rows each, the last band also taking any remainder rows, in the
+    /// same order every time. Used to lay out sibling views when no
+    /// layout tree is available to consult.
+    pub fn split_rows(self, count: u16) -> Vec<Rect> {
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let band_height = self.height / count;
+        (0..count)
+            .map(|i| {
+                let y = self.y + band_height * i;
+                let height = if i + 1 == count {
+                    self.height - band_height * i
+                } else {
+                    band_height
+                };
+                Rect::new(self.x, y, self.width, height)
+            })
+            .collect()
+    }
+}
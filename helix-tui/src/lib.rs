@@ -0,0 +1,4 @@
+pub mod backend;
+pub mod buffer;
+pub mod layout;
+pub mod style;
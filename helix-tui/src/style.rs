@@ -0,0 +1,74 @@
+/// A terminal color. Mirrors the subset of ANSI/truecolor values the
+/// renderer actually produces; `Reset` leaves the terminal's default
+/// color in place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Color {
+    #[default]
+    Reset,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+bitflags::bitflags! {
+    /// Text attributes layered on top of a cell's colors (bold, italic, …).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct Modifier: u16 {
+        const BOLD = 0b0000_0001;
+        const DIM = 0b0000_0010;
+        const ITALIC = 0b0000_0100;
+        const UNDERLINED = 0b0000_1000;
+        const REVERSED = 0b0001_0000;
+        const CROSSED_OUT = 0b0010_0000;
+    }
+}
+
+/// The foreground/background colors and modifiers applied to a single
+/// terminal cell. Equality is used by the render-snapshot formatter to
+/// group contiguous cells sharing a style into a single run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub add_modifier: Modifier,
+    pub sub_modifier: Modifier,
+}
+
+impl Style {
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn add_modifier(mut self, modifier: Modifier) -> Self {
+        self.add_modifier.insert(modifier);
+        self.sub_modifier.remove(modifier);
+        self
+    }
+
+    /// Applies `patch` on top of `self`, following the same "only set
+    /// fields are overridden" semantics as every other style patch in the
+    /// renderer (used when layering selection/diagnostic highlights over
+    /// syntax highlighting).
+    pub fn patch(mut self, patch: Style) -> Self {
+        self.fg = patch.fg.or(self.fg);
+        self.bg = patch.bg.or(self.bg);
+        self.add_modifier.remove(patch.sub_modifier);
+        self.add_modifier.insert(patch.add_modifier);
+        self.sub_modifier.remove(patch.add_modifier);
+        self.sub_modifier.insert(patch.sub_modifier);
+        self
+    }
+}